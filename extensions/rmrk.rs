@@ -0,0 +1,100 @@
+use ink_env::{AccountId, Environment};
+use ink_lang as ink;
+use ink_prelude::vec::Vec;
+
+pub type NftId = u32;
+pub type CollectionId = u32;
+pub type ResourceId = u32;
+
+#[derive(scale::Encode, scale::Decode, Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum RCErrorCode {
+    Failed,
+    CollectionNotCreated,
+    CollectionAlreadyCreated,
+}
+
+#[derive(scale::Encode, scale::Decode, Debug)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum RCError {
+    ErrorCode(RCErrorCode),
+}
+
+/// Chain extension exposed by the RoosterDao runtime (RMRK pallets)
+#[ink::chain_extension]
+pub trait RmrkExt {
+    type ErrorCode = RCErrorCode;
+
+    #[ink(extension = 1, returns_result = false)]
+    fn read_nft(caller_id: AccountId, collection_id: CollectionId, nft_id: NftId) -> bool;
+
+    #[ink(extension = 2, returns_result = false)]
+    fn mint_nft(
+        contract_address: AccountId,
+        owner: AccountId,
+        collection_id: CollectionId,
+        metadata: Vec<u8>,
+    ) -> Option<NftId>;
+
+    #[ink(extension = 3, returns_result = false)]
+    fn create_collection(
+        contract_address: AccountId,
+        metadata: Vec<u8>,
+        symbol: Vec<u8>,
+    ) -> Option<CollectionId>;
+
+    #[ink(extension = 4, returns_result = false)]
+    fn add_resource(
+        contract_address: AccountId,
+        collection_id: CollectionId,
+        nft_id: NftId,
+        metadata: Vec<u8>,
+    ) -> Option<ResourceId>;
+
+    #[ink(extension = 5, returns_result = false)]
+    fn remove_resource(
+        contract_address: AccountId,
+        collection_id: CollectionId,
+        nft_id: NftId,
+        resource_id: ResourceId,
+    );
+}
+
+impl From<RCErrorCode> for RCError {
+    fn from(error_code: RCErrorCode) -> Self {
+        Self::ErrorCode(error_code)
+    }
+}
+
+impl From<scale::Error> for RCError {
+    fn from(_: scale::Error) -> Self {
+        panic!("encountered unexpected invalid SCALE encoding")
+    }
+}
+
+impl ink_env::chain_extension::FromStatusCode for RCErrorCode {
+    fn from_status_code(status_code: u32) -> Result<(), Self> {
+        match status_code {
+            0 => Ok(()),
+            1 => Err(Self::Failed),
+            _ => panic!("encountered unknown status code"),
+        }
+    }
+}
+
+/// Environment of the RoosterDao runtime, use as `#[openbrush::contract(env = ...)]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum CustomEnvironment {}
+
+impl Environment for CustomEnvironment {
+    const MAX_EVENT_TOPICS: usize = <ink_env::DefaultEnvironment as Environment>::MAX_EVENT_TOPICS;
+
+    type AccountId = <ink_env::DefaultEnvironment as Environment>::AccountId;
+    type Balance = <ink_env::DefaultEnvironment as Environment>::Balance;
+    type Hash = <ink_env::DefaultEnvironment as Environment>::Hash;
+    type BlockNumber = <ink_env::DefaultEnvironment as Environment>::BlockNumber;
+    type Timestamp = <ink_env::DefaultEnvironment as Environment>::Timestamp;
+
+    type ChainExtension = RmrkExt;
+}
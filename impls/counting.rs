@@ -0,0 +1,125 @@
+use crate::traits::{
+    counting::*,
+    governor::ProposalVote,
+};
+
+pub struct SimpleMajority;
+
+impl CountingStrategy for SimpleMajority {
+    fn vote_weight(&self, voting_power: u32) -> u32 {
+        voting_power
+    }
+
+    fn vote_succeeded(&self, votes: &ProposalVote) -> bool {
+        votes.votes_for > votes.votes_against
+    }
+}
+
+pub struct Supermajority {
+    /// Required share of for votes, in percent of the for and against votes
+    pub percentage: u8,
+}
+
+impl CountingStrategy for Supermajority {
+    fn vote_weight(&self, voting_power: u32) -> u32 {
+        voting_power
+    }
+
+    fn vote_succeeded(&self, votes: &ProposalVote) -> bool {
+        let votes_for = votes.votes_for as u64;
+        let votes_cast = votes_for + votes.votes_against as u64;
+
+        votes_for > 0 && votes_for * 100 >= self.percentage as u64 * votes_cast
+    }
+}
+
+pub struct Quadratic;
+
+impl CountingStrategy for Quadratic {
+    fn vote_weight(&self, voting_power: u32) -> u32 {
+        integer_sqrt(voting_power)
+    }
+
+    fn vote_succeeded(&self, votes: &ProposalVote) -> bool {
+        votes.votes_for > votes.votes_against
+    }
+}
+
+impl CountingStrategy for CountingMode {
+    fn vote_weight(&self, voting_power: u32) -> u32 {
+        match self {
+            CountingMode::SimpleMajority => SimpleMajority.vote_weight(voting_power),
+            CountingMode::Supermajority(percentage) => Supermajority { percentage: *percentage }.vote_weight(voting_power),
+            CountingMode::Quadratic => Quadratic.vote_weight(voting_power),
+        }
+    }
+
+    fn vote_succeeded(&self, votes: &ProposalVote) -> bool {
+        match self {
+            CountingMode::SimpleMajority => SimpleMajority.vote_succeeded(votes),
+            CountingMode::Supermajority(percentage) => Supermajority { percentage: *percentage }.vote_succeeded(votes),
+            CountingMode::Quadratic => Quadratic.vote_succeeded(votes),
+        }
+    }
+}
+
+/// Largest integer whose square does not exceed `value`
+fn integer_sqrt(value: u32) -> u32 {
+    if value < 2 {
+        return value
+    }
+
+    // Newton's method, starting above the root
+    let value = value as u64;
+    let mut x = value;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tally(votes_for: u32, votes_against: u32) -> ProposalVote {
+        ProposalVote {
+            votes_for,
+            votes_against,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn integer_sqrt_works() {
+        assert_eq!(integer_sqrt(0), 0);
+        assert_eq!(integer_sqrt(1), 1);
+        assert_eq!(integer_sqrt(4), 2);
+        assert_eq!(integer_sqrt(8), 2);
+        assert_eq!(integer_sqrt(9), 3);
+        assert_eq!(integer_sqrt(65536), 256);
+        assert_eq!(integer_sqrt(u32::MAX), 65535);
+    }
+
+    #[test]
+    fn supermajority_works() {
+        let counting = CountingMode::Supermajority(60);
+
+        // exactly at the threshold succeeds, just below fails
+        assert!(counting.vote_succeeded(&tally(3, 2)));
+        assert!(counting.vote_succeeded(&tally(60, 40)));
+        assert!(!counting.vote_succeeded(&tally(59, 41)));
+        assert!(!counting.vote_succeeded(&tally(0, 0)));
+        assert_eq!(counting.vote_weight(9), 9);
+    }
+
+    #[test]
+    fn quadratic_works() {
+        assert_eq!(CountingMode::Quadratic.vote_weight(9), 3);
+        assert_eq!(CountingMode::Quadratic.vote_weight(10), 3);
+        assert!(CountingMode::Quadratic.vote_succeeded(&tally(2, 1)));
+        assert!(!CountingMode::Quadratic.vote_succeeded(&tally(1, 1)));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod counting;
+pub mod votes;
@@ -0,0 +1,59 @@
+use crate::traits::{
+    governor::GovernorError,
+    votes::*,
+};
+
+use ink_env::{
+    call::{
+        build_call,
+        Call,
+        ExecutionInput,
+        Selector,
+    },
+    DefaultEnvironment,
+};
+
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+
+impl VotingPower for VotingPowerSource {
+    fn token_votes(&self, account: AccountId, timepoint: Option<Timestamp>) -> Result<u32, GovernorError> {
+        let token = match self.token {
+            Some(token) => token,
+            None => return Ok(0),
+        };
+
+        // calls are built by hand rather than through a contract ref, which traps when the token fails.
+        // Timepoints are sent as u64 whatever the clock mode, as `PSP22Votes::get_past_votes` declares
+        let result = match timepoint {
+            Some(timepoint) => {
+                build_call::<DefaultEnvironment>()
+                    .call_type(Call::new().callee(token))
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(ink_lang::selector_bytes!("PSP22Votes::get_past_votes")))
+                            .push_arg(account)
+                            .push_arg(timepoint),
+                    )
+                    .returns::<Balance>()
+                    .fire()
+            }
+            None => {
+                build_call::<DefaultEnvironment>()
+                    .call_type(Call::new().callee(token))
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(ink_lang::selector_bytes!("PSP22Votes::get_votes")))
+                            .push_arg(account),
+                    )
+                    .returns::<Balance>()
+                    .fire()
+            }
+        };
+        let balance = result.map_err(|_| GovernorError::TokenCallFailed)?;
+
+        let votes = balance / self.units_per_vote.max(1);
+        Ok(votes.min(u32::MAX as u128) as u32)
+    }
+}
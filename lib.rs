@@ -1,5 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![feature(min_specialization)]
 
-//pub mod impls;
+pub mod impls;
+pub mod extensions;
 pub mod traits;
@@ -0,0 +1,52 @@
+use ink_storage::traits::{
+    forward_allocate_packed,
+    KeyPtr,
+    PackedAllocate,
+    PackedLayout,
+    SpreadAllocate,
+    SpreadLayout,
+};
+use ink_primitives::Key;
+
+#[cfg(feature = "std")]
+use ink_storage::traits::StorageLayout;
+
+use crate::traits::governor::ProposalVote;
+
+/// Decides how votes are weighted and when a proposal succeeds
+pub trait CountingStrategy {
+    /// Returns the weight added to the tally for a voter holding `voting_power`
+    fn vote_weight(&self, voting_power: u32) -> u32;
+
+    /// Returns whether the tallies of a closed proposal make it succeed
+    fn vote_succeeded(&self, votes: &ProposalVote) -> bool;
+}
+
+/// Counting strategy selected by a governor instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum CountingMode {
+    /// More votes for than against
+    SimpleMajority,
+    /// At least the given percentage of the for and against votes are for
+    Supermajority(u8),
+    /// Like `SimpleMajority`, but each voter weighs the square root of its voting power
+    Quadratic,
+}
+
+impl Default for CountingMode {
+    fn default() -> Self {
+        CountingMode::SimpleMajority
+    }
+}
+
+impl SpreadAllocate for CountingMode {
+    fn allocate_spread(ptr: &mut KeyPtr) -> Self {
+        forward_allocate_packed::<Self>(ptr)
+    }
+}
+
+impl PackedAllocate for CountingMode {
+    #[inline]
+    fn allocate_packed(&mut self, _at: &Key) {}
+}
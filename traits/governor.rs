@@ -1,12 +1,18 @@
 use ink_storage::traits::{
+    forward_allocate_packed,
+    KeyPtr,
+    PackedAllocate,
     PackedLayout,
+    SpreadAllocate,
     SpreadLayout,
 };
+use ink_primitives::Key;
 use ink_prelude::string::String;
 use ink_prelude::vec::Vec;
 
 use openbrush::{
     contracts::timelock_controller::*,
+    contracts::pausable::PausableError,
     traits::Timestamp,
     // storage::{
     //     Mapping,
@@ -15,30 +21,23 @@ use openbrush::{
 
     traits::{
         AccountId,
+        Balance,
         BlockNumber,
     },
 };
 
-#[derive(scale::Encode, scale::Decode, Debug)]
-#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-pub enum RCErrorCode {
-    Failed,
-    CollectionNotCreated,
-    CollectionAlreadyCreated,
-}
-
-#[derive(scale::Encode, scale::Decode, Debug)]
-#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-pub enum RCError {
-    ErrorCode(RCErrorCode),
-}
-
+use crate::traits::counting::CountingMode;
+use crate::traits::votes::VotingPowerSource;
 
-pub type NftId = u32;
-pub type CollectionId = u32;
-pub type ResourceId = u32;
+pub use crate::extensions::rmrk::{
+    NftId,
+    CollectionId,
+    ResourceId,
+    RCErrorCode,
+    RCError,
+};
 
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum ProposalState {
     Pending,
@@ -51,7 +50,7 @@ pub enum ProposalState {
     Executed
 }
 
-#[derive(scale::Encode, scale::Decode, Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(scale::Encode, scale::Decode, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum GovernorError {
     InsufficientVotingPower,
@@ -60,12 +59,82 @@ pub enum GovernorError {
     NotOpenForVoting,
     HasAlreadyVoted,
     VoteHasNotSucceeded,
+    ProposalNotQueued,
     NotOwner,
     InsufficientAmount,
     AlreadyOwner,
     MintFailed,
+    MaxSupplyReached,
+    NotAllowlisted,
+    NotInvited,
+    InvalidMembershipMode,
+    CallerMustBeGovernance,
+    PauseTimeoutNotElapsed,
+    ProposalCannotBeCanceled,
+    InvalidCountingMode,
+    InvalidProposalKind,
+    InvalidOptionCount,
+    InvalidOption,
+    InvalidVoteSplit,
+    InvalidLevelWeights,
+    InvalidVotingPowerSource,
+    TokenCallFailed,
+    NoDelegate,
+    NoDeposit,
+    DepositNotClaimable,
+    InsufficientTreasury,
+    TransferFailed,
+    OptimisticProposalsDisabled,
+    InvalidDelegation,
+    RCErrorCode(RCErrorCode),
+    AccessControlError(AccessControlError),
+    PausableError(PausableError),
+    TimelockControllerError(TimelockControllerError),
+}
+
+impl From<RCErrorCode> for GovernorError {
+    fn from(error_code: RCErrorCode) -> Self {
+        GovernorError::RCErrorCode(error_code)
+    }
+}
+
+impl From<RCError> for GovernorError {
+    fn from(error: RCError) -> Self {
+        match error {
+            RCError::ErrorCode(error_code) => GovernorError::RCErrorCode(error_code),
+        }
+    }
 }
 
+impl From<AccessControlError> for GovernorError {
+    fn from(error: AccessControlError) -> Self {
+        GovernorError::AccessControlError(error)
+    }
+}
+
+impl From<PausableError> for GovernorError {
+    fn from(error: PausableError) -> Self {
+        GovernorError::PausableError(error)
+    }
+}
+
+impl From<TimelockControllerError> for GovernorError {
+    fn from(error: TimelockControllerError) -> Self {
+        GovernorError::TimelockControllerError(error)
+    }
+}
+
+/// Admin of the governor roles, only held by the governor itself
+pub const GOVERNANCE_ROLE: RoleType = ink_lang::selector_id!("GOVERNANCE_ROLE");
+/// Role allowed to create proposals, granting it to the zero account opens it to every member
+pub const GOVERNOR_PROPOSER_ROLE: RoleType = ink_lang::selector_id!("GOVERNOR_PROPOSER_ROLE");
+/// Role allowed to cancel proposals that have not been executed
+pub const CANCELLER_ROLE: RoleType = ink_lang::selector_id!("CANCELLER_ROLE");
+/// Role allowed to pause governance actions during an incident
+pub const GUARDIAN_ROLE: RoleType = ink_lang::selector_id!("GUARDIAN_ROLE");
+/// Role allowed to change the voting parameters and the NFT price
+pub const PARAMETER_SETTER_ROLE: RoleType = ink_lang::selector_id!("PARAMETER_SETTER_ROLE");
+
 #[derive(scale::Encode, scale::Decode, Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum VoteType {
@@ -88,17 +157,210 @@ pub struct ProposalVote {
 }
 
 
+/// How a voter's weight was counted on a proposal
+#[derive(Default, Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct VoteReceipt {
+    pub votes_against: u32,
+    pub votes_for:     u32,
+    pub votes_abstain: u32,
+    /// Option voted on a multiple-choice proposal, weighted by `votes_for`
+    pub option: Option<u32>,
+}
 
 #[derive(Default, Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout)]
 //#[derive(Default, Debug, SpreadLayout,)]
 #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
 pub struct ProposalCore {
+    pub kind: ProposalKind,
+    pub category: ProposalCategory,
+    pub proposer: AccountId,
+    pub description_hash: [u8; 32],
     pub vote_start: Timestamp,
     pub vote_end:   Timestamp,
+    /// Quorum in force when the proposal was created
+    pub quorum: u32,
+    /// Counting mode in force when the proposal was created
+    pub counting_mode: CountingMode,
+    /// Against votes vetoing an optimistic proposal, taken when the proposal was created
+    pub veto_threshold: u32,
     pub executed: bool,
     pub canceled: bool,
 }
 
+/// Maximum number of options of a multiple-choice proposal
+pub const MAX_PROPOSAL_OPTIONS: u32 = 16;
+
+/// Delegates of a member with their share of its votes
+#[derive(Default, Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct Delegation {
+    pub delegates: Vec<(AccountId, u32)>,
+    /// Timepoint from which the votes revert to the member, a block number
+    /// or a timestamp depending on the clock mode
+    pub expiry: Option<Timestamp>,
+}
+
+/// Maximum number of delegates a member can split its votes between
+pub const MAX_DELEGATES: u32 = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum ProposalKind {
+    /// Voted with `VoteType`, executes its single transaction
+    Standard,
+    /// Voted by option index, executes the transaction of the winning option
+    MultipleChoice,
+    /// Voted with `VoteType`, succeeds unless the veto threshold of against votes is reached
+    Optimistic,
+}
+
+impl Default for ProposalKind {
+    fn default() -> Self {
+        ProposalKind::Standard
+    }
+}
+
+/// Topic of a proposal, members can pick a different delegate for each category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum ProposalCategory {
+    /// Voted with the default delegations
+    General,
+    Treasury,
+    Tech,
+    Community,
+}
+
+impl Default for ProposalCategory {
+    fn default() -> Self {
+        ProposalCategory::General
+    }
+}
+
+/// Clock used for voting windows, snapshots and `get_past_votes`, see EIP-6372
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum ClockMode {
+    Timestamp,
+    BlockNumber,
+}
+
+impl Default for ClockMode {
+    fn default() -> Self {
+        ClockMode::Timestamp
+    }
+}
+
+impl SpreadAllocate for ClockMode {
+    fn allocate_spread(ptr: &mut KeyPtr) -> Self {
+        forward_allocate_packed::<Self>(ptr)
+    }
+}
+
+impl PackedAllocate for ClockMode {
+    #[inline]
+    fn allocate_packed(&mut self, _at: &Key) {}
+}
+
+/// Storable copy of a `Transaction`, which does not implement the storage layout traits
+#[derive(Default, Debug, Clone, PartialEq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct ProposalTransaction {
+    pub callee: AccountId,
+    pub selector: [u8; 4],
+    pub input: Vec<u8>,
+    pub transferred_value: Balance,
+    pub gas_limit: u64,
+}
+
+impl From<Transaction> for ProposalTransaction {
+    fn from(transaction: Transaction) -> Self {
+        ProposalTransaction {
+            callee: transaction.callee,
+            selector: transaction.selector,
+            input: transaction.input,
+            transferred_value: transaction.transferred_value,
+            gas_limit: transaction.gas_limit,
+        }
+    }
+}
+
+impl From<ProposalTransaction> for Transaction {
+    fn from(transaction: ProposalTransaction) -> Self {
+        Transaction {
+            callee: transaction.callee,
+            selector: transaction.selector,
+            input: transaction.input,
+            transferred_value: transaction.transferred_value,
+            gas_limit: transaction.gas_limit,
+        }
+    }
+}
+
+/// Everything needed to reconstruct and execute a proposal without an event indexer
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ProposalDetails {
+    pub proposal_id: OperationId,
+    pub proposer: AccountId,
+    pub description_hash: [u8; 32],
+    pub category: ProposalCategory,
+    /// Transaction of the proposal, the default transaction for multiple choice proposals
+    pub transaction: Transaction,
+    /// Options of a multiple choice proposal, empty for other kinds
+    pub options: Vec<Transaction>,
+    pub vote_start: Timestamp,
+    pub vote_end: Timestamp,
+    pub state: ProposalState,
+}
+
+/// Compact view of a proposal, without the list of voters
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ProposalInfo {
+    pub proposal_id: OperationId,
+    pub proposer: AccountId,
+    pub vote_start: Timestamp,
+    pub vote_end: Timestamp,
+    pub state: ProposalState,
+    pub votes_against: u32,
+    pub votes_for: u32,
+    pub votes_abstain: u32,
+    /// Votes of each option of a multiple-choice proposal, whose three tallies above stay at zero.
+    /// Empty for other proposals
+    pub option_votes: Vec<u32>,
+}
+
+/// How new members can join the DAO through `become_member`
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum MembershipMode {
+    /// Anyone paying the NFT price can join
+    Open,
+    /// Only accounts included in the Merkle tree with the given root can join
+    Allowlist([u8; 32]),
+    /// Only accounts invited by an existing member can join
+    Invite,
+}
+
+impl Default for MembershipMode {
+    fn default() -> Self {
+        MembershipMode::Open
+    }
+}
+
+impl SpreadAllocate for MembershipMode {
+    fn allocate_spread(ptr: &mut KeyPtr) -> Self {
+        forward_allocate_packed::<Self>(ptr)
+    }
+}
+
+impl PackedAllocate for MembershipMode {
+    #[inline]
+    fn allocate_packed(&mut self, _at: &Key) {}
+}
+
 #[openbrush::trait_definition]
 pub trait Governor {
     //read functions
@@ -116,6 +378,12 @@ pub trait Governor {
 
     #[ink(message)]
     fn proposal_votes(&self, proposal_id: OperationId) -> (u32,u32,u32);
+
+    #[ink(message)]
+    fn get_receipt(&self, proposal_id: OperationId, account: AccountId) -> Option<VoteReceipt>;
+
+    #[ink(message)]
+    fn proposal_option_votes(&self, proposal_id: OperationId) -> Vec<u32>;
         
     #[ink(message)]
     fn state(&self, proposal_id: OperationId) -> ProposalState;
@@ -130,32 +398,194 @@ pub trait Governor {
     fn hash_proposal(&self, transaction: Transaction, description_hash: [u8; 32]) -> OperationId;
 
     #[ink(message)] 
-    fn get_past_votes(&self, account: AccountId, block: BlockNumber) -> u32;
+    fn get_past_votes(&self, account: AccountId, timepoint: Timestamp) -> u32;
+
+    #[ink(message)]
+    fn clock(&self) -> Timestamp;
+
+    #[ink(message)]
+    fn clock_mode(&self) -> String;
 
     #[ink(message)]
     fn get_votes(&self, account: AccountId, blocknumber_o: Option<BlockNumber>) -> u32;
 
+    #[ink(message)]
+    fn delegates(&self, account: AccountId) -> Delegation;
+
+    #[ink(message)]
+    fn delegators_of(&self, delegate: AccountId, offset: u32, limit: u32) -> Vec<AccountId>;
+
+    #[ink(message)]
+    fn delegation_history(&self, account: AccountId, offset: u32, limit: u32) -> Vec<(BlockNumber,Timestamp,ProposalCategory,Delegation)>;
+
+    #[ink(message)]
+    fn category_delegates(&self, account: AccountId, category: ProposalCategory) -> Delegation;
+
+    #[ink(message)]
+    fn get_past_category_votes(&self, account: AccountId, timepoint: Timestamp, category: ProposalCategory) -> u32;
+
     #[ink(message)]
     fn list_owners(&self) -> Vec<(AccountId,NftId,u32)>;
 
+    #[ink(message)]
+    fn owner_count(&self) -> u32;
+
+    #[ink(message)]
+    fn list_owners_paged(&self, offset: u32, limit: u32) -> Vec<(AccountId,NftId,u32)>;
+
+    #[ink(message)]
+    fn get_membership_mode(&self) -> MembershipMode;
+
+    #[ink(message)]
+    fn proposal_count(&self) -> u32;
+
+    #[ink(message)]
+    fn get_proposal(&self, proposal_id: OperationId) -> Result<ProposalDetails, GovernorError>;
+
+    #[ink(message)]
+    fn list_proposals_paged(&self, offset: u32, limit: u32, state_filter: Option<ProposalState>) -> Vec<ProposalInfo>;
+
+    #[ink(message)]
+    fn get_counting_mode(&self) -> CountingMode;
+
+    #[ink(message)]
+    fn vote_change_allowed(&self) -> bool;
+
+    #[ink(message)]
+    fn quorum(&self) -> u32;
+
+    #[ink(message)]
+    fn get_proposal_deposit(&self) -> Balance;
+
+    #[ink(message)]
+    fn veto_threshold(&self) -> u32;
+
+    #[ink(message)]
+    fn held_deposits(&self) -> Balance;
+
+    #[ink(message)]
+    fn deposit_of(&self, proposal_id: OperationId) -> Option<(AccountId,Balance)>;
+
+    #[ink(message)]
+    fn get_level_weights(&self) -> Vec<u32>;
+
+    #[ink(message)]
+    fn get_voting_power_source(&self) -> VotingPowerSource;
+
+    #[ink(message)]
+    fn late_quorum_vote_extension(&self) -> Timestamp;
+
+    #[ink(message)]
+    fn roles_of(&self, account: AccountId) -> Vec<RoleType>;
+
 
     //write functions
     #[ink(message)]
-    fn create_collection(&mut self) -> Result<(), RCError>;
+    fn create_collection(&mut self) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn create_collection_metadata(&mut self, metadata: String, symbol: String, max_supply: Option<u32>) -> Result<(), GovernorError>;
 
     #[ink(message)]
     fn cast_vote(&mut self, proposal_id: OperationId, vote: VoteType, ) -> Result<(),GovernorError>;
 
+    #[ink(message)]
+    fn cast_vote_fractional(&mut self, proposal_id: OperationId, votes_for: u32, votes_against: u32, votes_abstain: u32) -> Result<(),GovernorError>;
+
+    #[ink(message)]
+    fn cast_vote_option(&mut self, proposal_id: OperationId, option: u32) -> Result<(),GovernorError>;
+
+    #[ink(message)]
+    fn queue(&mut self, proposal_id: OperationId) -> Result<(), GovernorError>;
+
     #[ink(message)]
     fn execute(&mut self, proposal_id: OperationId) -> Result<(), GovernorError>;
 
+    #[ink(message)]
+    fn execute_with(&mut self, transaction: Transaction, description_hash: [u8; 32]) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn cancel(&mut self, proposal_id: OperationId) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn set_voting_delay(&mut self, voting_delay: Timestamp) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn set_voting_period(&mut self, voting_period: Timestamp) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn set_counting_mode(&mut self, counting_mode: CountingMode) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn set_vote_change_allowed(&mut self, allowed: bool) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn set_voting_power_source(&mut self, source: VotingPowerSource) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn set_level_weights(&mut self, level_weights: Vec<u32>) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn set_veto_threshold(&mut self, veto_threshold: u32) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn set_proposal_deposit(&mut self, deposit: Balance) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn claim_deposit(&mut self, proposal_id: OperationId) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn set_quorum(&mut self, quorum: u32) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn set_late_quorum_vote_extension(&mut self, extension: Timestamp) -> Result<(), GovernorError>;
+
+    #[ink(message)]
+    fn set_nft_price(&mut self, price: Balance) -> Result<(), GovernorError>;
+
     #[ink(message)]
     fn propose(&mut self, transaction: Transaction, description: String) -> Result<OperationId, GovernorError>;
 
     #[ink(message)]
-    fn delegate(&mut self,delegate: AccountId,) -> Result<(),GovernorError>;
+    fn propose_in_category(&mut self, transaction: Transaction, description: String, category: ProposalCategory) -> Result<OperationId, GovernorError>;
+
+    #[ink(message)]
+    fn propose_optimistic(&mut self, transaction: Transaction, description: String) -> Result<OperationId, GovernorError>;
+
+    #[ink(message)]
+    fn propose_multiple_choice(&mut self, options: Vec<Transaction>, description: String, category: ProposalCategory) -> Result<OperationId, GovernorError>;
+
+    #[ink(message)]
+    fn delegate(&mut self, delegate: AccountId, expiry: Option<Timestamp>) -> Result<(),GovernorError>;
+
+    #[ink(message)]
+    fn delegate_split(&mut self, delegates: Vec<(AccountId,u32)>, expiry: Option<Timestamp>) -> Result<(),GovernorError>;
+
+    #[ink(message)]
+    fn delegate_category(&mut self, category: ProposalCategory, delegates: Vec<(AccountId,u32)>, expiry: Option<Timestamp>) -> Result<(),GovernorError>;
+
+    #[ink(message)]
+    fn undelegate(&mut self) -> Result<(),GovernorError>;
+
+    #[ink(message)]
+    fn invite(&mut self, account: AccountId) -> Result<(),GovernorError>;
+
+    #[ink(message)]
+    fn set_membership_mode(&mut self, mode: MembershipMode) -> Result<(),GovernorError>;
+
+    #[ink(message)]
+    fn pause(&mut self) -> Result<(),GovernorError>;
+
+    #[ink(message)]
+    fn unpause(&mut self) -> Result<(),GovernorError>;
+
+    #[ink(message)]
+    fn set_pause_timeout(&mut self, pause_timeout: Timestamp) -> Result<(),GovernorError>;
 
     //payable functions
     #[ink(message,payable)]
     fn become_member(&mut self) -> Result<(),GovernorError>;
+
+    #[ink(message,payable)]
+    fn become_member_with_proof(&mut self, proof: Vec<[u8; 32]>) -> Result<(),GovernorError>;
 }
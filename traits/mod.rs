@@ -1 +1,3 @@
-pub mod governor;
\ No newline at end of file
+pub mod governor;
+pub mod counting;
+pub mod votes;
\ No newline at end of file
@@ -0,0 +1,78 @@
+use ink_storage::traits::{
+    forward_allocate_packed,
+    KeyPtr,
+    PackedAllocate,
+    PackedLayout,
+    SpreadAllocate,
+    SpreadLayout,
+};
+use ink_primitives::Key;
+
+#[cfg(feature = "std")]
+use ink_storage::traits::StorageLayout;
+
+use crate::traits::governor::GovernorError;
+
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+
+/// Checkpointed voting power of a PSP22 governance token, the interface a
+/// voting power source token must implement. Governors call it with hand-built calls
+#[openbrush::trait_definition]
+pub trait PSP22Votes {
+    /// Returns the current votes delegated to account
+    #[ink(message)]
+    fn get_votes(&self, account: AccountId) -> Balance;
+
+    /// Returns the votes delegated to account at timepoint, in the clock of the token.
+    /// The timepoint is always encoded as a u64, block numbers included
+    #[ink(message)]
+    fn get_past_votes(&self, account: AccountId, timepoint: Timestamp) -> Balance;
+}
+
+/// Where a governor reads voting power from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct VotingPowerSource {
+    /// Members count with the votes delegated to them through their NFT
+    pub nft: bool,
+    /// `PSP22Votes` token whose checkpointed votes are added, it must use the clock of the governor
+    /// and take u64 timepoints in `get_past_votes`
+    pub token: Option<AccountId>,
+    /// Token units making up one vote
+    pub units_per_vote: Balance,
+}
+
+impl Default for VotingPowerSource {
+    fn default() -> Self {
+        VotingPowerSource {
+            nft: true,
+            token: None,
+            units_per_vote: 1,
+        }
+    }
+}
+
+impl SpreadAllocate for VotingPowerSource {
+    fn allocate_spread(ptr: &mut KeyPtr) -> Self {
+        forward_allocate_packed::<Self>(ptr)
+    }
+}
+
+impl PackedAllocate for VotingPowerSource {
+    #[inline]
+    fn allocate_packed(&mut self, _at: &Key) {}
+}
+
+/// Reads the voting power an account holds in a source
+pub trait VotingPower {
+    /// Returns the token votes of account at timepoint, or its current votes if timepoint is `None`
+    ///
+    /// # Errors
+    ///
+    ///     Returns with `TokenCallFailed` if the token cannot be read
+    fn token_votes(&self, account: AccountId, timepoint: Option<Timestamp>) -> Result<u32, GovernorError>;
+}